@@ -2,14 +2,19 @@ use clap::{Parser, Subcommand};
 use color_eyre::Result;
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use pickledb::{PickleDb, PickleDbDumpPolicy, SerializationMethod};
+use serde::{Deserialize, Serialize};
 use ratatui::{
     DefaultTerminal, Frame,
     layout::{Constraint, Layout},
     style::{Color, Modifier, Style},
-    text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
 };
-use std::{env, fs, process::Command};
+use std::{env, fs, io::Read, process::Command};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Style as SyntectStyle, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
@@ -51,7 +56,257 @@ enum Commands {
     Nine,
 }
 
-fn open_file_by_index(index: usize) -> color_eyre::Result<()> {
+/// Raw config file shape read from `config.toml`.
+#[derive(Debug, Default, Deserialize)]
+struct Config {
+    /// The editor command, either a bare program name or a `[program, args…]`
+    /// list. A `{file}` placeholder in any argument is replaced with the path.
+    editor: Option<EditorSpec>,
+}
+
+/// The two accepted spellings of the `editor` key in the config.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum EditorSpec {
+    /// A single program name, e.g. `editor = "nvim"`.
+    Command(String),
+    /// A program plus fixed arguments, e.g. `editor = ["code", "--goto"]`.
+    CommandArgs(Vec<String>),
+}
+
+/// A resolved editor launch command.
+#[derive(Debug, Clone)]
+struct EditorCommand {
+    program: String,
+    args: Vec<String>,
+}
+
+impl Default for EditorCommand {
+    fn default() -> Self {
+        // javelin historically shelled out to zed; keep that as the fallback.
+        Self {
+            program: "zed".to_string(),
+            args: Vec::new(),
+        }
+    }
+}
+
+impl EditorCommand {
+    /// Resolve the editor command, preferring the `JAVELIN_EDITOR` environment
+    /// variable, then the config file, then the built-in `zed` default.
+    fn resolve() -> Self {
+        if let Ok(raw) = env::var("JAVELIN_EDITOR") {
+            let mut parts = raw.split_whitespace().map(|s| s.to_string());
+            if let Some(program) = parts.next() {
+                return Self {
+                    program,
+                    args: parts.collect(),
+                };
+            }
+        }
+
+        Self::from_config().unwrap_or_default()
+    }
+
+    /// Attempt to read and parse `config.toml`, returning `None` when it is
+    /// absent, unreadable, malformed, or has no `editor` key set.
+    fn from_config() -> Option<Self> {
+        let path = dirs::config_dir()?.join("javelin").join("config.toml");
+        let contents = fs::read_to_string(path).ok()?;
+        let config: Config = toml::from_str(&contents).ok()?;
+        match config.editor? {
+            EditorSpec::Command(program) => Some(Self {
+                program,
+                args: Vec::new(),
+            }),
+            EditorSpec::CommandArgs(mut parts) => {
+                if parts.is_empty() {
+                    return None;
+                }
+                let program = parts.remove(0);
+                Some(Self {
+                    program,
+                    args: parts,
+                })
+            }
+        }
+    }
+
+    /// Spawn the editor for `file`, substituting `{file}` into any argument that
+    /// contains it, or appending the path when no placeholder is present.
+    fn launch(&self, file: &str) -> std::io::Result<std::process::ExitStatus> {
+        let mut cmd = Command::new(&self.program);
+        let mut substituted = false;
+        for arg in &self.args {
+            if arg.contains("{file}") {
+                cmd.arg(arg.replace("{file}", file));
+                substituted = true;
+            } else {
+                cmd.arg(arg);
+            }
+        }
+        if !substituted {
+            cmd.arg(file);
+        }
+        cmd.status()
+    }
+}
+
+/// Score added to a file each time it is opened.
+const ACCESS_INCREMENT: f64 = 1.0;
+/// Aged score below which a missing-on-disk entry becomes eligible for pruning.
+const PRUNE_SCORE_THRESHOLD: f64 = 1.0;
+/// Default number of idle days before a stale, low-score entry is swept.
+const DEFAULT_STALE_DAYS: u64 = 90;
+/// Number of lines read and highlighted for the preview pane.
+const PREVIEW_LINES: usize = 100;
+/// Cap on bytes read for a preview, guarding against huge files.
+const PREVIEW_MAX_BYTES: usize = 8 * 1024;
+
+/// A cached, syntax-highlighted preview of a single file.
+struct Preview {
+    /// Modification time (unix seconds) the preview was rendered from.
+    mtime: u64,
+    /// Pre-rendered lines, ready to drop into a [`Paragraph`].
+    lines: Vec<Line<'static>>,
+}
+
+/// A tracked file together with the frecency statistics used to rank it.
+///
+/// Stored as a JSON array per project, replacing the old flat list of paths.
+/// The `#[serde(default)]` attributes let records that lack the statistics
+/// fields deserialize cleanly; databases written by older versions, which
+/// stored plain path strings, are migrated by [`load_records`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FileRecord {
+    /// Absolute path to the file.
+    path: String,
+    /// Accumulated access score, incremented on every open.
+    #[serde(default)]
+    score: f64,
+    /// Unix timestamp (seconds) of the last access.
+    #[serde(default)]
+    last_access: u64,
+    /// When set, the entry keeps its manual position and is skipped by auto-sort.
+    #[serde(default)]
+    pinned: bool,
+}
+
+impl FileRecord {
+    /// A freshly tracked file, accessed now.
+    fn new(path: String) -> Self {
+        Self {
+            path,
+            score: ACCESS_INCREMENT,
+            last_access: now_secs(),
+            pinned: false,
+        }
+    }
+
+    /// Bump the score and refresh the access timestamp.
+    fn touch(&mut self) {
+        self.score += ACCESS_INCREMENT;
+        self.last_access = now_secs();
+    }
+
+    /// The score aged by a zoxide-style decay factor based on how long ago the
+    /// file was last accessed.
+    fn aged_score(&self, now: u64) -> f64 {
+        let elapsed = now.saturating_sub(self.last_access);
+        let factor = if elapsed < 3_600 {
+            4.0
+        } else if elapsed < 86_400 {
+            2.0
+        } else if elapsed < 604_800 {
+            0.5
+        } else {
+            0.25
+        };
+        self.score * factor
+    }
+}
+
+/// Current unix time in whole seconds.
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Load the per-project records, transparently migrating an older database that
+/// stored a plain `Vec<String>` of paths.
+fn load_records(db: &PickleDb, key: &str) -> Vec<FileRecord> {
+    if let Some(records) = db.get::<Vec<FileRecord>>(key) {
+        records
+    } else if let Some(paths) = db.get::<Vec<String>>(key) {
+        paths.into_iter().map(FileRecord::new).collect()
+    } else {
+        Vec::new()
+    }
+}
+
+/// Whether a record is eligible to be swept: not pinned, its file is `missing`
+/// on disk, it has been idle for longer than `stale_cutoff_secs`, and its aged
+/// score has decayed below [`PRUNE_SCORE_THRESHOLD`].
+fn is_sweepable(rec: &FileRecord, now: u64, stale_cutoff_secs: u64, missing: bool) -> bool {
+    if rec.pinned {
+        return false;
+    }
+    let idle = now.saturating_sub(rec.last_access);
+    missing && idle > stale_cutoff_secs && rec.aged_score(now) < PRUNE_SCORE_THRESHOLD
+}
+
+/// Reorder `records` in place by aged score (descending), leaving pinned entries
+/// fixed at their current positions.
+fn sort_by_frecency(records: &mut [FileRecord]) {
+    let now = now_secs();
+    let mut unpinned: Vec<FileRecord> = records.iter().filter(|r| !r.pinned).cloned().collect();
+    unpinned.sort_by(|a, b| {
+        b.aged_score(now)
+            .partial_cmp(&a.aged_score(now))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut next = unpinned.into_iter();
+    for slot in records.iter_mut() {
+        if !slot.pinned {
+            if let Some(rec) = next.next() {
+                *slot = rec;
+            }
+        }
+    }
+}
+
+/// Convert a syntect-highlighted range into an owned ratatui [`Span`], dropping
+/// any trailing newline so it doesn't break the [`Paragraph`] layout.
+fn syntect_span(style: SyntectStyle, text: &str) -> Span<'static> {
+    let fg = style.foreground;
+    Span::styled(
+        text.trim_end_matches('\n').to_string(),
+        Style::default().fg(Color::Rgb(fg.r, fg.g, fg.b)),
+    )
+}
+
+/// Render a stored absolute path relative to `current_dir` when possible,
+/// falling back to the full path otherwise.
+fn relative_display(file: &str, current_dir: &std::path::Path) -> String {
+    std::path::Path::new(file)
+        .strip_prefix(current_dir)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| file.to_string())
+}
+
+/// Case-insensitive subsequence match: every character of `needle` appears in
+/// `haystack` in order. An empty needle always matches.
+fn subsequence_match(haystack: &str, needle: &str) -> bool {
+    let mut chars = haystack.chars();
+    needle
+        .chars()
+        .all(|nc| chars.any(|hc| hc.eq_ignore_ascii_case(&nc)))
+}
+
+fn open_file_by_index(index: usize, editor: &EditorCommand) -> color_eyre::Result<()> {
     let data_dir = dirs::data_dir()
         .ok_or_else(|| color_eyre::eyre::eyre!("Failed to determine data directory"))?
         .join("javelin");
@@ -63,7 +318,7 @@ fn open_file_by_index(index: usize) -> color_eyre::Result<()> {
         return Ok(());
     }
 
-    let db = PickleDb::load(
+    let mut db = PickleDb::load(
         &db_path,
         PickleDbDumpPolicy::DumpUponRequest,
         SerializationMethod::Json,
@@ -80,7 +335,9 @@ fn open_file_by_index(index: usize) -> color_eyre::Result<()> {
         return Ok(());
     }
 
-    let files: Vec<String> = db.get(&project_key).unwrap_or_default();
+    let mut files = load_records(&db, &project_key);
+    // Present the files in the same frecency order the TUI would.
+    sort_by_frecency(&mut files);
 
     if index >= files.len() {
         eprintln!(
@@ -91,8 +348,13 @@ fn open_file_by_index(index: usize) -> color_eyre::Result<()> {
         return Ok(());
     }
 
-    let file = &files[index];
-    Command::new("zed").arg(file).status()?;
+    files[index].touch();
+    let file = files[index].path.clone();
+    db.set(&project_key, &files)?;
+    db.dump()?;
+    if let Err(e) = editor.launch(&file) {
+        eprintln!("Failed to launch editor '{}': {}", editor.program, e);
+    }
 
     Ok(())
 }
@@ -101,6 +363,7 @@ fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
 
     let cli = Cli::parse();
+    let editor = EditorCommand::resolve();
 
     match cli.command {
         Some(Commands::Clean) => {
@@ -117,15 +380,15 @@ fn main() -> color_eyre::Result<()> {
             }
             Ok(())
         }
-        Some(Commands::One) => open_file_by_index(0),
-        Some(Commands::Two) => open_file_by_index(1),
-        Some(Commands::Three) => open_file_by_index(2),
-        Some(Commands::Four) => open_file_by_index(3),
-        Some(Commands::Five) => open_file_by_index(4),
-        Some(Commands::Six) => open_file_by_index(5),
-        Some(Commands::Seven) => open_file_by_index(6),
-        Some(Commands::Eight) => open_file_by_index(7),
-        Some(Commands::Nine) => open_file_by_index(8),
+        Some(Commands::One) => open_file_by_index(0, &editor),
+        Some(Commands::Two) => open_file_by_index(1, &editor),
+        Some(Commands::Three) => open_file_by_index(2, &editor),
+        Some(Commands::Four) => open_file_by_index(3, &editor),
+        Some(Commands::Five) => open_file_by_index(4, &editor),
+        Some(Commands::Six) => open_file_by_index(5, &editor),
+        Some(Commands::Seven) => open_file_by_index(6, &editor),
+        Some(Commands::Eight) => open_file_by_index(7, &editor),
+        Some(Commands::Nine) => open_file_by_index(8, &editor),
         None => {
             let terminal = ratatui::init();
             let result = App::new()?.run(terminal);
@@ -135,6 +398,15 @@ fn main() -> color_eyre::Result<()> {
     }
 }
 
+/// The current input mode of the [`App`].
+#[derive(PartialEq)]
+enum Mode {
+    /// Default mode: navigation and the usual key bindings.
+    Normal,
+    /// Interactive filter: keystrokes narrow the visible list.
+    Filter,
+}
+
 pub struct App {
     /// Is the application running?
     running: bool,
@@ -142,10 +414,32 @@ pub struct App {
     db: PickleDb,
     /// List state for navigation
     list_state: ListState,
-    /// Cached file list
-    files: Vec<String>,
+    /// Cached file list with frecency statistics
+    files: Vec<FileRecord>,
     /// Current project key
     project_key: String,
+    /// Current input mode
+    mode: Mode,
+    /// The live filter query (only meaningful in [`Mode::Filter`])
+    filter_query: String,
+    /// Indices into `files` for the entries currently rendered
+    filtered_indices: Vec<usize>,
+    /// The resolved command used to open files
+    editor: EditorCommand,
+    /// Message shown in the info pane in place of the usual line (e.g. a
+    /// launch failure); once set it stays until a later message replaces it
+    status_message: Option<String>,
+    /// Paths currently marked for batch actions. Keyed by path rather than
+    /// position so reordering (`Shift+J/K`, auto-sort) can't misresolve a mark.
+    marked: std::collections::HashSet<String>,
+    /// Syntax definitions used to highlight the preview pane
+    syntax_set: SyntaxSet,
+    /// Theme set used to colour the preview pane
+    theme_set: ThemeSet,
+    /// Rendered previews keyed by path, invalidated on mtime change
+    preview_cache: std::collections::HashMap<String, Preview>,
+    /// Whether each entry in `files` is missing on disk (parallel to `files`)
+    missing: Vec<bool>,
 }
 
 impl App {
@@ -187,6 +481,16 @@ impl App {
             list_state: ListState::default(),
             files: Vec::new(),
             project_key,
+            mode: Mode::Normal,
+            filter_query: String::new(),
+            filtered_indices: Vec::new(),
+            editor: EditorCommand::resolve(),
+            status_message: None,
+            marked: std::collections::HashSet::new(),
+            syntax_set: SyntaxSet::load_defaults_newlines(),
+            theme_set: ThemeSet::load_defaults(),
+            preview_cache: std::collections::HashMap::new(),
+            missing: Vec::new(),
         };
 
         app.load_files();
@@ -211,19 +515,41 @@ impl App {
             .constraints([Constraint::Min(0), Constraint::Length(3)])
             .split(frame.area());
 
+        // Split the upper region into the file list (left) and preview (right).
+        let panes = Layout::default()
+            .direction(ratatui::layout::Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(chunks[0]);
+
         let current_dir = env::current_dir().unwrap_or_default();
         let items: Vec<ListItem> = self
-            .files
+            .filtered_indices
             .iter()
             .enumerate()
-            .map(|(i, file)| {
+            .map(|(i, &file_idx)| {
+                let file = &self.files[file_idx];
                 // Try to make the path relative to current directory for display
-                let display_path = std::path::Path::new(file)
-                    .strip_prefix(&current_dir)
-                    .map(|p| p.to_string_lossy().to_string())
-                    .unwrap_or_else(|_| file.clone());
+                let display_path = relative_display(&file.path, &current_dir);
 
+                let marked = self.marked.contains(&file.path);
+                let missing = self.missing.get(file_idx).copied().unwrap_or(false);
+                let path_style = if missing {
+                    Style::default().fg(Color::Red)
+                } else if marked {
+                    Style::default().fg(Color::Green)
+                } else {
+                    Style::default()
+                };
+                let label = if missing {
+                    format!("{} (missing)", display_path)
+                } else {
+                    display_path
+                };
                 let content = Line::from(vec![
+                    Span::styled(
+                        if marked { "● " } else { "  " },
+                        Style::default().fg(Color::Green),
+                    ),
                     Span::styled(
                         format!(
                             "{} ",
@@ -235,7 +561,7 @@ impl App {
                         ),
                         Style::default().fg(Color::Yellow),
                     ),
-                    Span::raw(display_path),
+                    Span::styled(label, path_style),
                 ]);
                 ListItem::new(content)
             })
@@ -258,7 +584,41 @@ impl App {
             )
             .highlight_symbol(">> ");
 
-        frame.render_stateful_widget(files_list, chunks[0], &mut self.list_state);
+        frame.render_stateful_widget(files_list, panes[0], &mut self.list_state);
+
+        // Draw the syntax-highlighted preview of the highlighted file.
+        let preview_text = match self.selected_file_index() {
+            Some(file_idx) => {
+                let path = self.files[file_idx].path.clone();
+                Text::from(self.preview_lines(&path))
+            }
+            None => Text::raw(""),
+        };
+        let preview = Paragraph::new(preview_text)
+            .block(Block::default().borders(Borders::ALL).title("Preview"));
+        frame.render_widget(preview, panes[1]);
+
+        // In filter mode the bottom pane becomes the filter input line.
+        if self.mode == Mode::Filter {
+            let filter_line = ratatui::widgets::Paragraph::new(format!(
+                "Filter: {}",
+                self.filter_query
+            ))
+            .block(Block::default().borders(Borders::ALL))
+            .style(Style::default().fg(Color::Yellow));
+            frame.render_widget(filter_line, chunks[1]);
+            return;
+        }
+
+        // A pending status message (e.g. an editor launch failure) takes
+        // priority over the usual informational line.
+        if let Some(message) = &self.status_message {
+            let status = ratatui::widgets::Paragraph::new(message.clone())
+                .block(Block::default().borders(Borders::ALL))
+                .style(Style::default().fg(Color::Red));
+            frame.render_widget(status, chunks[1]);
+            return;
+        }
 
         // Show current file that would be added with 'a'
         let current_file_info = if let Ok(file) = env::var("ZED_FILE") {
@@ -268,7 +628,7 @@ impl App {
                 .map(|p| p.to_string_lossy().to_string())
                 .unwrap_or_else(|_| file.clone());
 
-            if self.files.contains(&file) {
+            if self.files.iter().any(|r| r.path == file) {
                 format!("Current file: {} (already in list)", display_path)
             } else {
                 format!("Press 'a' to add: {}", display_path)
@@ -301,32 +661,74 @@ impl App {
 
     /// Handles the key events and updates the state of [`App`].
     fn on_key_event(&mut self, key: KeyEvent) {
+        if self.mode == Mode::Filter {
+            self.on_filter_key_event(key);
+            return;
+        }
+
         match (key.modifiers, key.code) {
             (_, KeyCode::Esc | KeyCode::Char('q'))
             | (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) => self.quit(),
             (_, KeyCode::Char('j')) => self.next(),
             (_, KeyCode::Char('k')) => self.previous(),
             (_, KeyCode::Char('a')) => self.add_current_file(),
-            (_, KeyCode::Char('d')) => self.delete_selected_file(),
+            (_, KeyCode::Char('d')) => self.delete_selected_files(),
+            (_, KeyCode::Char('f')) => self.enter_filter_mode(),
+            (_, KeyCode::Char(' ')) => self.toggle_mark(),
             (KeyModifiers::SHIFT, KeyCode::Char('J')) => self.move_down(),
             (KeyModifiers::SHIFT, KeyCode::Char('K')) => self.move_up(),
-            (_, KeyCode::Enter) => {
-                if let Some(selected) = self.list_state.selected() {
-                    self.open_file(selected);
-                    self.quit();
-                }
-            }
+            (KeyModifiers::SHIFT, KeyCode::Char('D')) => self.clear_marks(),
+            (KeyModifiers::SHIFT, KeyCode::Char('X')) => self.prune_missing(),
+            (_, KeyCode::Enter) => self.open_selection_and_quit(),
             (_, KeyCode::Char(c)) if c.is_numeric() => {
                 let index = c.to_digit(10).unwrap() as usize;
-                if index > 0 && index <= self.files.len() {
-                    self.open_file(index - 1);
-                    self.quit();
+                if index > 0 && index <= self.filtered_indices.len() {
+                    let file_idx = self.filtered_indices[index - 1];
+                    if self.open_file(file_idx) {
+                        self.quit();
+                    }
                 }
             }
             _ => {}
         }
     }
 
+    /// Handle key events while in [`Mode::Filter`].
+    fn on_filter_key_event(&mut self, key: KeyEvent) {
+        match (key.modifiers, key.code) {
+            (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) => self.quit(),
+            (_, KeyCode::Esc) => {
+                self.filter_query.clear();
+                self.mode = Mode::Normal;
+                self.recompute_filtered();
+            }
+            (_, KeyCode::Enter) => self.open_selection_and_quit(),
+            (_, KeyCode::Backspace) => {
+                self.filter_query.pop();
+                self.recompute_filtered();
+            }
+            (_, KeyCode::Char(c)) => {
+                self.filter_query.push(c);
+                self.recompute_filtered();
+            }
+            _ => {}
+        }
+    }
+
+    /// Enter filter mode, starting from an empty query.
+    fn enter_filter_mode(&mut self) {
+        self.mode = Mode::Filter;
+        self.filter_query.clear();
+        self.recompute_filtered();
+    }
+
+    /// Map the current list selection back to an index into `self.files`.
+    fn selected_file_index(&self) -> Option<usize> {
+        self.list_state
+            .selected()
+            .and_then(|sel| self.filtered_indices.get(sel).copied())
+    }
+
     /// Set running to false to quit the application.
     fn quit(&mut self) {
         self.running = false;
@@ -335,30 +737,101 @@ impl App {
     /// Load files from the database
     fn load_files(&mut self) {
         if self.db.exists(&self.project_key) {
-            self.files = self
-                .db
-                .get::<Vec<String>>(&self.project_key)
-                .unwrap_or_default();
+            self.files = load_records(&self.db, &self.project_key);
+            self.sweep_stale();
+            sort_by_frecency(&mut self.files);
+            self.save_files();
             if !self.files.is_empty() {
                 self.list_state.select(Some(0));
             }
         }
+        self.recompute_filtered();
+    }
+
+    /// Drop entries whose path no longer exists on disk *and* whose aged score
+    /// has decayed below [`PRUNE_SCORE_THRESHOLD`] after sitting untouched for
+    /// more than [`DEFAULT_STALE_DAYS`] days. Pinned entries are never swept.
+    fn sweep_stale(&mut self) {
+        let now = now_secs();
+        let stale_cutoff = DEFAULT_STALE_DAYS * 86_400;
+        self.files.retain(|rec| {
+            let missing = !std::path::Path::new(&rec.path).exists();
+            !is_sweepable(rec, now, stale_cutoff, missing)
+        });
+    }
+
+    /// Recompute `filtered_indices` from the current `filter_query`. In normal
+    /// mode (empty query) this is simply every index in `files`; otherwise only
+    /// entries whose displayed relative path matches the query are kept. The
+    /// selection is clamped to stay within the new view.
+    fn recompute_filtered(&mut self) {
+        let current_dir = env::current_dir().unwrap_or_default();
+        let query = self.filter_query.clone();
+        self.filtered_indices = self
+            .files
+            .iter()
+            .enumerate()
+            .filter(|(_, file)| {
+                query.is_empty()
+                    || subsequence_match(&relative_display(&file.path, &current_dir), &query)
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        if self.filtered_indices.is_empty() {
+            self.list_state.select(None);
+        } else {
+            let clamped = self
+                .list_state
+                .selected()
+                .unwrap_or(0)
+                .min(self.filtered_indices.len() - 1);
+            self.list_state.select(Some(clamped));
+        }
     }
 
     /// Save files to the database
     fn save_files(&mut self) {
         self.db.set(&self.project_key, &self.files).unwrap();
+        self.refresh_missing();
+    }
+
+    /// Recompute the `missing` flags by stat-ing each stored path.
+    fn refresh_missing(&mut self) {
+        self.missing = self
+            .files
+            .iter()
+            .map(|rec| !std::path::Path::new(&rec.path).exists())
+            .collect();
+    }
+
+    /// Remove every entry whose file no longer exists on disk (bound to
+    /// `Shift+X`) and persist the result.
+    fn prune_missing(&mut self) {
+        self.marked.clear();
+        self.files
+            .retain(|rec| std::path::Path::new(&rec.path).exists());
+        self.save_files();
+        self.recompute_filtered();
+
+        if self.filtered_indices.is_empty() {
+            self.list_state.select(None);
+        } else if let Some(selected) = self.list_state.selected() {
+            if selected >= self.filtered_indices.len() {
+                self.list_state.select(Some(self.filtered_indices.len() - 1));
+            }
+        }
     }
 
     /// Move to the next file in the list
     fn next(&mut self) {
-        if self.files.is_empty() {
+        if self.filtered_indices.is_empty() {
             return;
         }
 
         let i = match self.list_state.selected() {
             Some(i) => {
-                if i >= self.files.len() - 1 {
+                if i >= self.filtered_indices.len() - 1 {
                     0
                 } else {
                     i + 1
@@ -371,14 +844,14 @@ impl App {
 
     /// Move to the previous file in the list
     fn previous(&mut self) {
-        if self.files.is_empty() {
+        if self.filtered_indices.is_empty() {
             return;
         }
 
         let i = match self.list_state.selected() {
             Some(i) => {
                 if i == 0 {
-                    self.files.len() - 1
+                    self.filtered_indices.len() - 1
                 } else {
                     i - 1
                 }
@@ -391,47 +864,246 @@ impl App {
     /// Add the current file from ZED_FILE environment variable
     fn add_current_file(&mut self) {
         if let Ok(file) = env::var("ZED_FILE") {
-            if !self.files.contains(&file) {
-                self.files.push(file);
+            if !self.files.iter().any(|r| r.path == file) {
+                self.files.push(FileRecord::new(file));
                 self.save_files();
                 if self.files.len() == 1 {
                     self.list_state.select(Some(0));
                 }
+                self.recompute_filtered();
+            }
+        }
+    }
+
+    /// Delete the marked entries in one pass, or the highlighted entry when
+    /// nothing is marked. Marks are matched by path, so a single `retain`
+    /// removes them all regardless of position.
+    fn delete_selected_files(&mut self) {
+        if self.marked.is_empty() {
+            self.delete_selected_file();
+            return;
+        }
+
+        self.files.retain(|r| !self.marked.contains(&r.path));
+        self.marked.clear();
+        self.save_files();
+        self.recompute_filtered();
+
+        if self.filtered_indices.is_empty() {
+            self.list_state.select(None);
+        } else if let Some(selected) = self.list_state.selected() {
+            if selected >= self.filtered_indices.len() {
+                self.list_state.select(Some(self.filtered_indices.len() - 1));
             }
         }
     }
 
     /// Delete the currently selected file
     fn delete_selected_file(&mut self) {
-        if let Some(selected) = self.list_state.selected() {
-            if selected < self.files.len() {
-                self.files.remove(selected);
-                self.save_files();
+        if let Some(file_idx) = self.selected_file_index() {
+            self.files.remove(file_idx);
+            self.save_files();
+            self.recompute_filtered();
 
-                if self.files.is_empty() {
-                    self.list_state.select(None);
-                } else if selected >= self.files.len() {
-                    self.list_state.select(Some(self.files.len() - 1));
+            if self.filtered_indices.is_empty() {
+                self.list_state.select(None);
+            } else if let Some(selected) = self.list_state.selected() {
+                if selected >= self.filtered_indices.len() {
+                    self.list_state.select(Some(self.filtered_indices.len() - 1));
                 }
             }
         }
     }
 
-    /// Open a file at the given index with zed
-    fn open_file(&self, index: usize) {
-        if index < self.files.len() {
-            let file = &self.files[index];
+    /// Return the highlighted file's preview lines, rebuilding and caching them
+    /// only when the file's modification time has changed since last render.
+    fn preview_lines(&mut self, path: &str) -> Vec<Line<'static>> {
+        let mtime = fs::metadata(path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs());
+
+        let mtime = match mtime {
+            Some(mtime) => mtime,
+            None => {
+                return vec![Line::from(Span::styled(
+                    "(missing)",
+                    Style::default().fg(Color::Red),
+                ))];
+            }
+        };
+
+        let fresh = self
+            .preview_cache
+            .get(path)
+            .map(|p| p.mtime == mtime)
+            .unwrap_or(false);
+        if !fresh {
+            let preview = self.build_preview(path, mtime);
+            self.preview_cache.insert(path.to_string(), preview);
+        }
+
+        self.preview_cache[path].lines.clone()
+    }
+
+    /// Read the first [`PREVIEW_LINES`] lines of `path` (capped at
+    /// [`PREVIEW_MAX_BYTES`]) and highlight them with syntect, tokenizing by the
+    /// file extension. Binary files (those containing a NUL byte) get a
+    /// placeholder instead of being rendered.
+    fn build_preview(&self, path: &str, mtime: u64) -> Preview {
+        // Read at most PREVIEW_MAX_BYTES so an oversized file never gets slurped
+        // into memory in full.
+        let bytes = match fs::File::open(path).and_then(|f| {
+            let mut buf = Vec::new();
+            f.take(PREVIEW_MAX_BYTES as u64).read_to_end(&mut buf)?;
+            Ok(buf)
+        }) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                return Preview {
+                    mtime,
+                    lines: vec![Line::from(Span::styled(
+                        format!("Unable to read file: {}", e),
+                        Style::default().fg(Color::Red),
+                    ))],
+                };
+            }
+        };
+
+        if bytes.contains(&0) {
+            return Preview {
+                mtime,
+                lines: vec![Line::from(Span::styled(
+                    "binary file",
+                    Style::default().fg(Color::DarkGray),
+                ))],
+            };
+        }
+
+        let contents = String::from_utf8_lossy(&bytes);
+        let extension = std::path::Path::new(path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("");
+        let syntax = self
+            .syntax_set
+            .find_syntax_by_extension(extension)
+            .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
+        let theme = &self.theme_set.themes["base16-ocean.dark"];
+        let mut highlighter = HighlightLines::new(syntax, theme);
+
+        let lines = LinesWithEndings::from(&contents)
+            .take(PREVIEW_LINES)
+            .map(|line| {
+                let ranges = highlighter
+                    .highlight_line(line, &self.syntax_set)
+                    .unwrap_or_default();
+                let spans: Vec<Span<'static>> = ranges
+                    .iter()
+                    .map(|(style, text)| syntect_span(*style, text))
+                    .collect();
+                Line::from(spans)
+            })
+            .collect();
+
+        Preview { mtime, lines }
+    }
+
+    /// Toggle the batch mark on the highlighted entry.
+    fn toggle_mark(&mut self) {
+        if let Some(idx) = self.selected_file_index() {
+            let path = self.files[idx].path.clone();
+            if !self.marked.insert(path.clone()) {
+                self.marked.remove(&path);
+            }
+        }
+    }
+
+    /// Clear every batch mark (bound to `Shift+D`).
+    fn clear_marks(&mut self) {
+        self.marked.clear();
+    }
+
+    /// Open the current selection: every marked entry when any are marked,
+    /// otherwise just the highlighted one. Returns whether the editor launched.
+    fn open_selection(&mut self) -> bool {
+        let indices: Vec<usize> = if self.marked.is_empty() {
+            match self.selected_file_index() {
+                Some(i) => vec![i],
+                None => return false,
+            }
+        } else {
+            // Resolve marked paths to their current positions, in list order.
+            self.files
+                .iter()
+                .enumerate()
+                .filter(|(_, r)| self.marked.contains(&r.path))
+                .map(|(i, _)| i)
+                .collect()
+        };
+        self.open_files(&indices)
+    }
 
-            // Use 'zed' command directly and ensure it completes
-            let _ = Command::new("zed").arg(file).status();
+    /// Open the current selection and, if the editor launched, quit.
+    fn open_selection_and_quit(&mut self) {
+        if self.open_selection() {
+            self.quit();
         }
     }
 
+    /// Open a single file at the given index with the configured editor.
+    fn open_file(&mut self, index: usize) -> bool {
+        self.open_files(&[index])
+    }
+
+    /// Open every file in `indices` with the configured editor, recording each
+    /// access so the entries' frecency scores rise for next time. Returns
+    /// whether all editors launched; on failure the error is reported in the
+    /// info pane.
+    fn open_files(&mut self, indices: &[usize]) -> bool {
+        let mut paths = Vec::new();
+        for &index in indices {
+            if index >= self.files.len() {
+                continue;
+            }
+            // Refuse to launch the editor on a file that is known to be gone.
+            if self.missing.get(index).copied().unwrap_or(false) {
+                self.status_message = Some(format!(
+                    "File is missing on disk: {}",
+                    self.files[index].path
+                ));
+                continue;
+            }
+            self.files[index].touch();
+            paths.push(self.files[index].path.clone());
+        }
+        if paths.is_empty() {
+            return false;
+        }
+
+        sort_by_frecency(&mut self.files);
+        self.save_files();
+
+        for path in &paths {
+            if let Err(e) = self.editor.launch(path) {
+                self.status_message = Some(format!(
+                    "Failed to launch editor '{}': {}",
+                    self.editor.program, e
+                ));
+                return false;
+            }
+        }
+        true
+    }
+
     /// Move the selected file down in the list
     fn move_down(&mut self) {
         if let Some(selected) = self.list_state.selected() {
             if selected < self.files.len() - 1 {
                 self.files.swap(selected, selected + 1);
+                // A manual move pins the entry so auto-sort leaves it alone.
+                self.files[selected + 1].pinned = true;
                 self.save_files();
                 self.list_state.select(Some(selected + 1));
             }
@@ -443,9 +1115,91 @@ impl App {
         if let Some(selected) = self.list_state.selected() {
             if selected > 0 {
                 self.files.swap(selected, selected - 1);
+                // A manual move pins the entry so auto-sort leaves it alone.
+                self.files[selected - 1].pinned = true;
                 self.save_files();
                 self.list_state.select(Some(selected - 1));
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(score: f64, last_access: u64) -> FileRecord {
+        FileRecord {
+            path: "x".to_string(),
+            score,
+            last_access,
+            pinned: false,
+        }
+    }
+
+    #[test]
+    fn aged_score_decay_buckets() {
+        let now = 1_000_000;
+        // Within the hour: ×4.
+        assert_eq!(record(1.0, now).aged_score(now), 4.0);
+        assert_eq!(record(1.0, now - 3_599).aged_score(now), 4.0);
+        // At exactly one hour it falls into the within-a-day bucket: ×2.
+        assert_eq!(record(1.0, now - 3_600).aged_score(now), 2.0);
+        assert_eq!(record(1.0, now - 86_399).aged_score(now), 2.0);
+        // At exactly a day: ×0.5.
+        assert_eq!(record(1.0, now - 86_400).aged_score(now), 0.5);
+        assert_eq!(record(1.0, now - 604_799).aged_score(now), 0.5);
+        // At exactly a week and beyond: ×0.25.
+        assert_eq!(record(1.0, now - 604_800).aged_score(now), 0.25);
+        assert_eq!(record(1.0, 0).aged_score(now), 0.25);
+    }
+
+    #[test]
+    fn sort_by_frecency_keeps_pinned_slots() {
+        // u64::MAX last_access keeps elapsed at 0, so ordering is driven by the
+        // raw score (all entries share the ×4 factor).
+        let mut records = vec![
+            record(1.0, u64::MAX),
+            record(5.0, u64::MAX),
+            record(3.0, u64::MAX),
+        ];
+        records[1].pinned = true; // pin the middle slot
+
+        sort_by_frecency(&mut records);
+
+        // The pinned entry stays put; the two unpinned slots are sorted desc.
+        assert_eq!(records[0].score, 3.0);
+        assert_eq!(records[1].score, 5.0);
+        assert_eq!(records[2].score, 1.0);
+        assert!(records[1].pinned);
+    }
+
+    #[test]
+    fn sweep_keeps_missing_but_recent() {
+        let now = 10_000_000;
+        let cutoff = DEFAULT_STALE_DAYS * 86_400;
+        // Missing on disk but accessed just now: must be kept.
+        let recent = record(1.0, now);
+        assert!(!is_sweepable(&recent, now, cutoff, true));
+        // Missing, idle well past the cutoff, decayed below the threshold: gone.
+        let stale = record(1.0, now - cutoff - 86_400);
+        assert!(is_sweepable(&stale, now, cutoff, true));
+        // Same stale timing but still present on disk: kept.
+        assert!(!is_sweepable(&stale, now, cutoff, false));
+        // Pinned entries are never swept.
+        let mut pinned = stale.clone();
+        pinned.pinned = true;
+        assert!(!is_sweepable(&pinned, now, cutoff, true));
+    }
+
+    #[test]
+    fn subsequence_match_is_case_insensitive() {
+        assert!(subsequence_match("src/Main.rs", "main"));
+        assert!(subsequence_match("src/Main.rs", "SRC"));
+        // Subsequence, not just substring.
+        assert!(subsequence_match("src/main.rs", "smr"));
+        assert!(!subsequence_match("src/main.rs", "xyz"));
+        // Empty needle always matches.
+        assert!(subsequence_match("anything", ""));
+    }
+}